@@ -1,37 +1,208 @@
 #![no_std]
 
 use core::{
+    cmp::Ordering,
     marker::PhantomData,
-    ops::{Add, BitAnd, BitOr, BitXor, Not, Shl, Shr, Sub},
+    ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Rem, Shl, Shr, Sub},
 };
 
+use generic_array::{ArrayLength, GenericArray};
 use typenum::{
-    IsGreaterOrEqual, IsLessOrEqual, Max, Min, Unsigned, U1, U128, U15, U16, U32, U47, U48, U49,
-    U6, U74, U80,
+    IsGreaterOrEqual, IsLess, IsLessOrEqual, Max, Min, Prod, Quot, Sum, Unsigned, U0, U1, U128,
+    U15, U16, U3, U32, U4, U47, U48, U49, U5, U6, U60, U63, U64, U65, U66, U7, U74, U8, U80, U82,
 };
 
+/// Bound satisfied by every `Width` we can actually back with a limb array.
+/// The `Limbs` associated type (rather than a free-standing `where` clause)
+/// is what lets a plain `Width: ValidWidth` bound carry the
+/// `ArrayLength<u64>` obligation to every caller.
+trait ValidWidth: Unsigned {
+    type Limbs: ArrayLength<u64>;
+}
+
+impl<W> ValidWidth for W
+where
+    W: Unsigned + Add<U63>,
+    Sum<W, U63>: Div<U64>,
+    Quot<Sum<W, U63>, U64>: ArrayLength<u64>,
+{
+    type Limbs = Quot<Sum<W, U63>, U64>;
+}
+
+/// Number of `u64` limbs needed to back a value of `Width` bits: `ceil(Width / 64)`.
+type Limbs<Width> = <Width as ValidWidth>::Limbs;
+
+/// Bound satisfied by every `Width` we can encode as a byte array.
+trait ByteWidth: Unsigned {
+    type Bytes: ArrayLength<u8>;
+}
+
+impl<W> ByteWidth for W
+where
+    W: Unsigned + Add<U7>,
+    Sum<W, U7>: Div<U8>,
+    Quot<Sum<W, U7>, U8>: ArrayLength<u8>,
+{
+    type Bytes = Quot<Sum<W, U7>, U8>;
+}
+
+/// Number of bytes needed to back a value of `Width` bits: `ceil(Width / 8)`.
+type Bytes<Width> = <Width as ByteWidth>::Bytes;
+
+/// Bound satisfied by every `Width` we can encode as hex.
+trait NibbleWidth: Unsigned {
+    type Nibbles: ArrayLength<u8>;
+}
+
+impl<W> NibbleWidth for W
+where
+    W: Unsigned + Add<U3>,
+    Sum<W, U3>: Div<U4>,
+    Quot<Sum<W, U3>, U4>: ArrayLength<u8>,
+{
+    type Nibbles = Quot<Sum<W, U3>, U4>;
+}
+
+/// Number of hex nibbles needed to back a value of `Width` bits: `ceil(Width / 4)`.
+type Nibbles<Width> = <Width as NibbleWidth>::Nibbles;
+
 // TODO: better Debug impl?
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
-struct MaxBits<Width: Unsigned> {
-    data: u128,
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+struct MaxBits<Width: ValidWidth> {
+    data: GenericArray<u64, Limbs<Width>>,
     _marker: PhantomData<*const Width>,
 }
 
-type MaximumWidth = U128;
+// `derive(Copy)` can't express the extra `ArrayType: Copy` bound the
+// generic-length backing needs, so it's spelled out by hand instead.
+impl<Width> Copy for MaxBits<Width>
+where
+    Width: ValidWidth,
+    GenericArray<u64, Limbs<Width>>: Copy,
+{
+}
+
+/// Copies as many limbs as will fit from `data` into a freshly zeroed array of
+/// the target length, used by `widen`, `narrow` and `Not` to reinterpret a
+/// value under a different limb count.
+fn resize<FromWidth, ToWidth>(
+    data: &GenericArray<u64, Limbs<FromWidth>>,
+) -> GenericArray<u64, Limbs<ToWidth>>
+where
+    FromWidth: ValidWidth,
+    ToWidth: ValidWidth,
+{
+    let mut out = GenericArray::<u64, Limbs<ToWidth>>::default();
+    for (dst, src) in out.iter_mut().zip(data.iter()) {
+        *dst = *src;
+    }
+    out
+}
+
+fn get_bit<N: ArrayLength<u64>>(limbs: &GenericArray<u64, N>, bit: u32) -> bool {
+    limbs
+        .get((bit / 64) as usize)
+        .map(|limb| (limb >> (bit % 64)) & 1 == 1)
+        .unwrap_or(false)
+}
+
+fn set_bit<N: ArrayLength<u64>>(limbs: &mut GenericArray<u64, N>, bit: u32) {
+    if let Some(limb) = limbs.get_mut((bit / 64) as usize) {
+        *limb |= 1 << (bit % 64);
+    }
+}
+
+/// Shifts `limbs` left by one bit in place and returns the bit that fell off
+/// the top, since the array itself has nowhere to put it.
+fn shl1<N: ArrayLength<u64>>(limbs: &mut GenericArray<u64, N>) -> bool {
+    let mut carry = 0u64;
+    for limb in limbs.iter_mut() {
+        let next_carry = *limb >> 63;
+        *limb = (*limb << 1) | carry;
+        carry = next_carry;
+    }
+    carry != 0
+}
+
+fn cmp_limbs<N: ArrayLength<u64>>(a: &GenericArray<u64, N>, b: &GenericArray<u64, N>) -> Ordering {
+    for i in (0..a.len()).rev() {
+        match a[i].cmp(&b[i]) {
+            Ordering::Equal => continue,
+            ord => return ord,
+        }
+    }
+    Ordering::Equal
+}
+
+fn sub_assign<N: ArrayLength<u64>>(a: &mut GenericArray<u64, N>, b: &GenericArray<u64, N>) {
+    let mut borrow = false;
+    for i in 0..a.len() {
+        let (diff, overflow1) = a[i].overflowing_sub(b[i]);
+        let (diff, overflow2) = diff.overflowing_sub(borrow as u64);
+        a[i] = diff;
+        borrow = overflow1 || overflow2;
+    }
+}
+
+/// Binary restoring division: walks the dividend's bits from the top down,
+/// shifting them into the remainder one at a time and subtracting the
+/// divisor whenever it fits. Quotient keeps the dividend's limb count,
+/// remainder keeps the divisor's.
+fn divmod<NumWidth, DenWidth>(
+    num: &GenericArray<u64, Limbs<NumWidth>>,
+    den: &GenericArray<u64, Limbs<DenWidth>>,
+) -> (
+    GenericArray<u64, Limbs<NumWidth>>,
+    GenericArray<u64, Limbs<DenWidth>>,
+)
+where
+    NumWidth: ValidWidth,
+    DenWidth: ValidWidth,
+{
+    if den.iter().all(|&limb| limb == 0) {
+        panic!("attempt to divide by zero");
+    }
+
+    let mut quotient = GenericArray::<u64, Limbs<NumWidth>>::default();
+    let mut remainder = GenericArray::<u64, Limbs<DenWidth>>::default();
+
+    for bit in (0..NumWidth::to_u32()).rev() {
+        // `remainder` has no spare bit above the divisor's own width, so a
+        // remainder that doubles past the top of the array would otherwise
+        // lose its high bit silently. Doubling a value smaller than `den`
+        // can only overflow the array by exactly one bit, and that bit
+        // alone already makes the remainder bigger than `den` (which fits
+        // within the array), so we can fold it straight into the
+        // subtract-or-not decision below instead of widening the register.
+        let overflow = shl1(&mut remainder);
+        if get_bit(num, bit) {
+            remainder[0] |= 1;
+        }
+        if overflow || cmp_limbs(&remainder, den) != Ordering::Less {
+            sub_assign(&mut remainder, den);
+            set_bit(&mut quotient, bit);
+        }
+    }
+
+    (quotient, remainder)
+}
 
 impl<Width> MaxBits<Width>
 where
-    Width: Unsigned,
+    Width: ValidWidth,
 {
-    fn fits(data: u128) -> bool
-    where
-        Width: Unsigned,
-    {
-        data.leading_zeros() >= (MaximumWidth::to_u32() - Width::to_u32())
+    fn fits(data: &GenericArray<u64, Limbs<Width>>) -> bool {
+        let backing_bits = Limbs::<Width>::to_u32() * 64;
+        let unused_bits = backing_bits - Width::to_u32();
+        if unused_bits == 0 {
+            return true;
+        }
+        let top_limb = data[data.len() - 1];
+        top_limb >> (64 - unused_bits) == 0
     }
 
-    pub fn new(data: u128) -> Option<Self> {
-        if Self::fits(data) {
+    pub fn new(data: GenericArray<u64, Limbs<Width>>) -> Option<Self> {
+        if Self::fits(&data) {
             Some(Self {
                 data,
                 _marker: PhantomData,
@@ -41,40 +212,392 @@ where
         }
     }
 
-    pub fn into_inner(self) -> u128 {
+    pub fn from_u64(value: u64) -> Option<Self> {
+        let mut data = GenericArray::<u64, Limbs<Width>>::default();
+        data[0] = value;
+        Self::new(data)
+    }
+
+    pub fn into_inner(self) -> GenericArray<u64, Limbs<Width>> {
         self.data
     }
 
     pub fn widen<Widened>(self) -> MaxBits<Widened>
     where
-        Widened: Unsigned + IsGreaterOrEqual<Width>,
+        Widened: ValidWidth + IsGreaterOrEqual<Width>,
     {
         MaxBits {
-            data: self.data,
+            data: resize::<Width, Widened>(&self.data),
             _marker: PhantomData,
         }
     }
 
     pub fn narrow<Narrowed>(self) -> Option<MaxBits<Narrowed>>
     where
-        Narrowed: Unsigned + IsLessOrEqual<Width>,
+        Narrowed: ValidWidth + IsLessOrEqual<Width>,
     {
-        MaxBits::<Narrowed>::new(self.data)
+        // `resize` only copies as many limbs as `Narrowed` has room for, so
+        // a value that doesn't fit because of bits in a limb `resize` never
+        // even looks at would otherwise slip past the `fits` check inside
+        // `MaxBits::new` below. Check the dropped high limbs ourselves first.
+        let kept_limbs = Limbs::<Narrowed>::to_usize();
+        if self.data[kept_limbs..].iter().any(|&limb| limb != 0) {
+            return None;
+        }
+        MaxBits::<Narrowed>::new(resize::<Width, Narrowed>(&self.data))
+    }
+
+    /// Number of bits actually occupied by the value, i.e. the position of
+    /// the highest set bit plus one. Zero for a zero value. Lets a caller
+    /// pick a safe `narrow` target at runtime before attempting the
+    /// type-level cast.
+    pub fn significant_bits(&self) -> u32 {
+        Width::to_u32() - self.leading_zeros()
+    }
+
+    /// Like `u32::leading_zeros`, but relative to `Width` rather than this
+    /// type's limb-array backing capacity: the always-zero padding bits
+    /// above `Width` (when it isn't a multiple of 64) don't count.
+    pub fn leading_zeros(&self) -> u32 {
+        let backing_bits = Limbs::<Width>::to_u32() * 64;
+        let padding_bits = backing_bits - Width::to_u32();
+        let mut zeros = 0;
+        for &limb in self.data.iter().rev() {
+            if limb == 0 {
+                zeros += 64;
+            } else {
+                zeros += limb.leading_zeros();
+                break;
+            }
+        }
+        zeros - padding_bits
+    }
+
+    /// Like `u32::trailing_zeros`, but a zero value reports `Width` rather
+    /// than the limb-array backing capacity.
+    pub fn trailing_zeros(&self) -> u32 {
+        let mut zeros = 0;
+        for &limb in self.data.iter() {
+            if limb == 0 {
+                zeros += 64;
+            } else {
+                zeros += limb.trailing_zeros();
+                return zeros;
+            }
+        }
+        Width::to_u32()
+    }
+
+    pub fn count_ones(&self) -> u32 {
+        self.data.iter().map(|limb| limb.count_ones()).sum()
+    }
+}
+
+/// Mask for the bits `[a, b)` within a single 64-bit limb. An empty range
+/// (`a >= b`) yields 0; that check also covers `b == 0`, the one case where
+/// `64 - b` would be a shift by 64.
+fn limb_mask(a: u32, b: u32) -> u64 {
+    if a >= b {
+        0
+    } else {
+        let low = if a == 0 { u64::MAX } else { u64::MAX << a };
+        let high = if b == 64 { u64::MAX } else { u64::MAX >> (64 - b) };
+        low & high
+    }
+}
+
+/// Mask for the bits `[lo, hi)` spread across a whole limb array.
+fn range_mask<N: ArrayLength<u64>>(lo: u32, hi: u32) -> GenericArray<u64, N> {
+    let mut mask = GenericArray::<u64, N>::default();
+    for (i, limb) in mask.iter_mut().enumerate() {
+        let base = (i as u32) * 64;
+        let a = lo.saturating_sub(base).min(64);
+        let b = hi.saturating_sub(base).min(64);
+        *limb = limb_mask(a, b);
+    }
+    mask
+}
+
+/// Shifts `data` right by `shift` bits, landing the result in a (possibly
+/// differently-sized) limb array. Bits shifted past the top of `data` read
+/// as zero; bits shifted past the bottom of the output are dropped.
+fn shift_right<N: ArrayLength<u64>, M: ArrayLength<u64>>(
+    data: &GenericArray<u64, N>,
+    shift: u32,
+) -> GenericArray<u64, M> {
+    let limb_shift = (shift / 64) as usize;
+    let bit_shift = shift % 64;
+    let mut out = GenericArray::<u64, M>::default();
+    for i in 0..out.len() {
+        let src = i + limb_shift;
+        let mut limb = data.get(src).copied().unwrap_or(0);
+        if bit_shift != 0 {
+            limb >>= bit_shift;
+            if let Some(upper) = data.get(src + 1) {
+                limb |= upper << (64 - bit_shift);
+            }
+        }
+        out[i] = limb;
+    }
+    out
+}
+
+impl<Width> MaxBits<Width>
+where
+    Width: ValidWidth,
+{
+    /// Sets every bit in `[lo, hi)` to `1`. `hi` may exceed `Width`, in which
+    /// case the result widens to cover it.
+    pub fn set_range<Lo, Hi>(self, _lo: Lo, _hi: Hi) -> MaxBits<<Width as Max<Hi>>::Output>
+    where
+        Lo: Unsigned + IsLessOrEqual<Hi>,
+        Hi: Unsigned,
+        Width: Max<Hi>,
+        <Width as Max<Hi>>::Output: ValidWidth,
+    {
+        let mut data = resize::<Width, <Width as Max<Hi>>::Output>(&self.data);
+        let mask = range_mask::<Limbs<<Width as Max<Hi>>::Output>>(Lo::to_u32(), Hi::to_u32());
+        for (limb, m) in data.iter_mut().zip(mask.iter()) {
+            *limb |= m;
+        }
+        MaxBits {
+            data,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Clears every bit in `[lo, hi)` to `0`.
+    pub fn clear_range<Lo, Hi>(self, _lo: Lo, _hi: Hi) -> Self
+    where
+        Lo: Unsigned + IsLessOrEqual<Hi>,
+        Hi: Unsigned + IsLessOrEqual<Width>,
+    {
+        let mut data = self.data;
+        let mask = range_mask::<Limbs<Width>>(Lo::to_u32(), Hi::to_u32());
+        for (limb, m) in data.iter_mut().zip(mask.iter()) {
+            *limb &= !m;
+        }
+        Self {
+            data,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Extracts the bits in `[lo, hi)`, shifted down to occupy `[0, hi - lo)`.
+    pub fn extract_range<Lo, Hi>(self, _lo: Lo, _hi: Hi) -> MaxBits<<Hi as Sub<Lo>>::Output>
+    where
+        Lo: Unsigned,
+        Hi: Unsigned + IsLessOrEqual<Width> + Sub<Lo>,
+        <Hi as Sub<Lo>>::Output: ValidWidth,
+    {
+        let mut data = shift_right::<Limbs<Width>, Limbs<<Hi as Sub<Lo>>::Output>>(
+            &self.data,
+            Lo::to_u32(),
+        );
+        let keep = range_mask::<Limbs<<Hi as Sub<Lo>>::Output>>(0, Hi::to_u32() - Lo::to_u32());
+        for (limb, m) in data.iter_mut().zip(keep.iter()) {
+            *limb &= m;
+        }
+        MaxBits {
+            data,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// An index into a `MaxBits<Width>`, guaranteed to be `< Width`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct BitIndex<Width> {
+    index: u32,
+    _marker: PhantomData<*const Width>,
+}
+
+impl<Width: Unsigned> BitIndex<Width> {
+    fn new(index: u32) -> Self {
+        debug_assert!(index < Width::to_u32());
+        Self {
+            index,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn to_u32(self) -> u32 {
+        self.index
+    }
+}
+
+/// Iterator over the set bit indices of a `MaxBits<Width>`, lowest first,
+/// each step stripping the lowest set bit with `limb & limb.wrapping_neg()`.
+struct SetBits<Width: ValidWidth> {
+    data: GenericArray<u64, Limbs<Width>>,
+    _marker: PhantomData<*const Width>,
+}
+
+impl<Width: ValidWidth> Iterator for SetBits<Width> {
+    type Item = BitIndex<Width>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (i, limb) in self.data.iter_mut().enumerate() {
+            if *limb != 0 {
+                let lowest = *limb & limb.wrapping_neg();
+                *limb &= !lowest;
+                return Some(BitIndex::new(i as u32 * 64 + lowest.trailing_zeros()));
+            }
+        }
+        None
+    }
+}
+
+impl<Width> MaxBits<Width>
+where
+    Width: ValidWidth,
+{
+    /// Whether bit `n` is set, where `n < Width` is enforced at the type level.
+    pub fn contains<N>(&self, _n: N) -> bool
+    where
+        N: Unsigned + IsLess<Width>,
+    {
+        get_bit(&self.data, N::to_u32())
+    }
+
+    /// The lowest set bit's index, or `None` if the value is zero.
+    pub fn min_set_bit(&self) -> Option<BitIndex<Width>> {
+        self.data
+            .iter()
+            .enumerate()
+            .find(|&(_, &limb)| limb != 0)
+            .map(|(i, &limb)| BitIndex::new(i as u32 * 64 + limb.trailing_zeros()))
+    }
+
+    /// The highest set bit's index, or `None` if the value is zero.
+    pub fn max_set_bit(&self) -> Option<BitIndex<Width>> {
+        self.data
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|&(_, &limb)| limb != 0)
+            .map(|(i, &limb)| BitIndex::new(i as u32 * 64 + (63 - limb.leading_zeros())))
+    }
+
+    /// An iterator over the set bit indices, lowest first.
+    pub fn set_bits(&self) -> SetBits<Width> {
+        SetBits {
+            data: self.data.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Width> MaxBits<Width>
+where
+    Width: ValidWidth + ByteWidth,
+{
+    pub fn to_be_bytes(&self) -> GenericArray<u8, Bytes<Width>> {
+        let mut bytes = GenericArray::<u8, Bytes<Width>>::default();
+        let last = bytes.len() - 1;
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            let byte_index = last - i;
+            let limb = self.data.get(byte_index / 8).copied().unwrap_or(0);
+            *byte = (limb >> ((byte_index % 8) * 8)) as u8;
+        }
+        bytes
+    }
+
+    pub fn to_le_bytes(&self) -> GenericArray<u8, Bytes<Width>> {
+        let mut bytes = GenericArray::<u8, Bytes<Width>>::default();
+        for (byte_index, byte) in bytes.iter_mut().enumerate() {
+            let limb = self.data.get(byte_index / 8).copied().unwrap_or(0);
+            *byte = (limb >> ((byte_index % 8) * 8)) as u8;
+        }
+        bytes
+    }
+
+    pub fn from_be_bytes(bytes: &GenericArray<u8, Bytes<Width>>) -> Option<Self> {
+        let last = bytes.len() - 1;
+        let mut data = GenericArray::<u64, Limbs<Width>>::default();
+        for (i, &byte) in bytes.iter().enumerate() {
+            let byte_index = last - i;
+            if let Some(limb) = data.get_mut(byte_index / 8) {
+                *limb |= (byte as u64) << ((byte_index % 8) * 8);
+            }
+        }
+        Self::new(data)
+    }
+
+    pub fn from_le_bytes(bytes: &GenericArray<u8, Bytes<Width>>) -> Option<Self> {
+        let mut data = GenericArray::<u64, Limbs<Width>>::default();
+        for (byte_index, &byte) in bytes.iter().enumerate() {
+            if let Some(limb) = data.get_mut(byte_index / 8) {
+                *limb |= (byte as u64) << ((byte_index % 8) * 8);
+            }
+        }
+        Self::new(data)
+    }
+}
+
+impl<Width> MaxBits<Width>
+where
+    Width: ValidWidth + NibbleWidth,
+{
+    /// Lowercase hex encoding, using the minimal number of nibbles for `Width`.
+    pub fn to_hex(&self) -> GenericArray<u8, Nibbles<Width>> {
+        let mut hex = GenericArray::<u8, Nibbles<Width>>::default();
+        let last = hex.len() - 1;
+        for (i, out) in hex.iter_mut().enumerate() {
+            let nibble_index = last - i;
+            let limb = self.data.get(nibble_index / 16).copied().unwrap_or(0);
+            let nibble = (limb >> ((nibble_index % 16) * 4)) & 0xf;
+            *out = char::from_digit(nibble as u32, 16).unwrap() as u8;
+        }
+        hex
+    }
+
+    pub fn from_hex(hex: &GenericArray<u8, Nibbles<Width>>) -> Option<Self> {
+        let last = hex.len() - 1;
+        let mut data = GenericArray::<u64, Limbs<Width>>::default();
+        for (i, &ch) in hex.iter().enumerate() {
+            let digit = (ch as char).to_digit(16)?;
+            let nibble_index = last - i;
+            if let Some(limb) = data.get_mut(nibble_index / 16) {
+                *limb |= (digit as u64) << ((nibble_index % 16) * 4);
+            }
+        }
+        Self::new(data)
     }
 }
 
 impl<Width, Shift> Shl<Shift> for MaxBits<Width>
 where
-    Width: Add<Shift> + Unsigned,
-    <Width as Add<Shift>>::Output: Unsigned,
+    Width: ValidWidth + Add<Shift>,
+    <Width as Add<Shift>>::Output: ValidWidth,
     Shift: Unsigned,
-    <Width as Add<Shift>>::Output: IsLessOrEqual<MaximumWidth>,
 {
     type Output = MaxBits<<Width as Add<Shift>>::Output>;
 
     fn shl(self, _: Shift) -> Self::Output {
+        let shift = Shift::to_usize();
+        let limb_shift = shift / 64;
+        let bit_shift = shift % 64;
+
+        let mut data =
+            GenericArray::<u64, Limbs<<Width as Add<Shift>>::Output>>::default();
+        for i in (0..data.len()).rev() {
+            let src = match i.checked_sub(limb_shift) {
+                Some(src) => src,
+                None => continue,
+            };
+            let mut limb = self.data.get(src).copied().unwrap_or(0);
+            if bit_shift != 0 {
+                limb <<= bit_shift;
+                if let Some(lower) = src.checked_sub(1).and_then(|s| self.data.get(s)) {
+                    limb |= lower >> (64 - bit_shift);
+                }
+            }
+            data[i] = limb;
+        }
+
         Self::Output {
-            data: self.data << Shift::to_u32(),
+            data,
             _marker: PhantomData,
         }
     }
@@ -82,15 +605,33 @@ where
 
 impl<Width, Shift> Shr<Shift> for MaxBits<Width>
 where
-    Width: Sub<Shift> + Unsigned,
-    <Width as Sub<Shift>>::Output: Unsigned,
+    Width: ValidWidth + Sub<Shift>,
+    <Width as Sub<Shift>>::Output: ValidWidth,
     Shift: Unsigned,
 {
     type Output = MaxBits<<Width as Sub<Shift>>::Output>;
 
     fn shr(self, _: Shift) -> Self::Output {
+        let shift = Shift::to_usize();
+        let limb_shift = shift / 64;
+        let bit_shift = shift % 64;
+
+        let mut data =
+            GenericArray::<u64, Limbs<<Width as Sub<Shift>>::Output>>::default();
+        for i in 0..data.len() {
+            let src = i + limb_shift;
+            let mut limb = self.data.get(src).copied().unwrap_or(0);
+            if bit_shift != 0 {
+                limb >>= bit_shift;
+                if let Some(upper) = self.data.get(src + 1) {
+                    limb |= upper << (64 - bit_shift);
+                }
+            }
+            data[i] = limb;
+        }
+
         Self::Output {
-            data: self.data >> Shift::to_u32(),
+            data,
             _marker: PhantomData,
         }
     }
@@ -98,15 +639,19 @@ where
 
 impl<Width, RhsWidth> BitOr<MaxBits<RhsWidth>> for MaxBits<Width>
 where
-    Width: Unsigned + Max<RhsWidth>,
-    RhsWidth: Unsigned,
-    <Width as Max<RhsWidth>>::Output: Unsigned,
+    Width: ValidWidth + Max<RhsWidth>,
+    RhsWidth: ValidWidth,
+    <Width as Max<RhsWidth>>::Output: ValidWidth,
 {
     type Output = MaxBits<<Width as Max<RhsWidth>>::Output>;
 
     fn bitor(self, rhs: MaxBits<RhsWidth>) -> Self::Output {
+        let mut data = GenericArray::<u64, Limbs<<Width as Max<RhsWidth>>::Output>>::default();
+        for (i, limb) in data.iter_mut().enumerate() {
+            *limb = self.data.get(i).copied().unwrap_or(0) | rhs.data.get(i).copied().unwrap_or(0);
+        }
         Self::Output {
-            data: self.data | rhs.data,
+            data,
             _marker: PhantomData,
         }
     }
@@ -114,15 +659,19 @@ where
 
 impl<Width, RhsWidth> BitAnd<MaxBits<RhsWidth>> for MaxBits<Width>
 where
-    Width: Unsigned + Min<RhsWidth>,
-    RhsWidth: Unsigned,
-    <Width as Min<RhsWidth>>::Output: Unsigned,
+    Width: ValidWidth + Min<RhsWidth>,
+    RhsWidth: ValidWidth,
+    <Width as Min<RhsWidth>>::Output: ValidWidth,
 {
     type Output = MaxBits<<Width as Min<RhsWidth>>::Output>;
 
     fn bitand(self, rhs: MaxBits<RhsWidth>) -> Self::Output {
+        let mut data = GenericArray::<u64, Limbs<<Width as Min<RhsWidth>>::Output>>::default();
+        for (i, limb) in data.iter_mut().enumerate() {
+            *limb = self.data.get(i).copied().unwrap_or(0) & rhs.data.get(i).copied().unwrap_or(0);
+        }
         Self::Output {
-            data: self.data & rhs.data,
+            data,
             _marker: PhantomData,
         }
     }
@@ -130,15 +679,19 @@ where
 
 impl<Width, RhsWidth> BitXor<MaxBits<RhsWidth>> for MaxBits<Width>
 where
-    Width: Unsigned + Max<RhsWidth>,
-    RhsWidth: Unsigned,
-    <Width as Max<RhsWidth>>::Output: Unsigned,
+    Width: ValidWidth + Max<RhsWidth>,
+    RhsWidth: ValidWidth,
+    <Width as Max<RhsWidth>>::Output: ValidWidth,
 {
     type Output = MaxBits<<Width as Max<RhsWidth>>::Output>;
 
     fn bitxor(self, rhs: MaxBits<RhsWidth>) -> Self::Output {
+        let mut data = GenericArray::<u64, Limbs<<Width as Max<RhsWidth>>::Output>>::default();
+        for (i, limb) in data.iter_mut().enumerate() {
+            *limb = self.data.get(i).copied().unwrap_or(0) ^ rhs.data.get(i).copied().unwrap_or(0);
+        }
         Self::Output {
-            data: self.data ^ rhs.data,
+            data,
             _marker: PhantomData,
         }
     }
@@ -146,15 +699,22 @@ where
 
 impl<Width> Not for MaxBits<Width>
 where
-    Width: Unsigned,
+    Width: ValidWidth,
+    Limbs<Width>: Mul<U64>,
+    Prod<Limbs<Width>, U64>: ValidWidth,
 {
-    // Not much we can do to improve this bound without tracking
-    // much more about the value, which doesn't seem reasonable
-    type Output = MaxBits<MaximumWidth>;
+    // Not much we can do to improve this bound without tracking which of the
+    // high bits were actually meaningful before the flip, so the result
+    // claims the full capacity of the backing limb array.
+    type Output = MaxBits<Prod<Limbs<Width>, U64>>;
 
     fn not(self) -> Self::Output {
+        let mut data = resize::<Width, Prod<Limbs<Width>, U64>>(&self.data);
+        for limb in data.iter_mut() {
+            *limb = !*limb;
+        }
         Self::Output {
-            data: !self.data,
+            data,
             _marker: PhantomData,
         }
     }
@@ -162,16 +722,28 @@ where
 
 impl<Width, RhsWidth> Add<MaxBits<RhsWidth>> for MaxBits<Width>
 where
-    Width: Unsigned + Max<RhsWidth>,
-    RhsWidth: Unsigned,
+    Width: ValidWidth + Max<RhsWidth>,
+    RhsWidth: ValidWidth,
     <Width as Max<RhsWidth>>::Output: Unsigned + Add<U1>,
-    <<Width as Max<RhsWidth>>::Output as Add<U1>>::Output: Unsigned + IsLessOrEqual<MaximumWidth>,
+    <<Width as Max<RhsWidth>>::Output as Add<U1>>::Output: ValidWidth,
 {
     type Output = MaxBits<<<Width as Max<RhsWidth>>::Output as Add<U1>>::Output>;
 
     fn add(self, rhs: MaxBits<RhsWidth>) -> Self::Output {
+        let mut data = GenericArray::<
+            u64,
+            Limbs<<<Width as Max<RhsWidth>>::Output as Add<U1>>::Output>,
+        >::default();
+        let mut carry: u128 = 0;
+        for (i, limb) in data.iter_mut().enumerate() {
+            let sum = self.data.get(i).copied().unwrap_or(0) as u128
+                + rhs.data.get(i).copied().unwrap_or(0) as u128
+                + carry;
+            *limb = sum as u64;
+            carry = sum >> 64;
+        }
         Self::Output {
-            data: self.data + rhs.data,
+            data,
             _marker: PhantomData,
         }
     }
@@ -179,30 +751,289 @@ where
 
 impl<Width, RhsWidth> Sub<MaxBits<RhsWidth>> for MaxBits<Width>
 where
-    Width: Unsigned + Sub<U1>,
-    RhsWidth: Unsigned + IsLessOrEqual<Width>,
-    <Width as Sub<U1>>::Output: Unsigned,
+    Width: ValidWidth + Max<RhsWidth>,
+    RhsWidth: ValidWidth,
+    <Width as Max<RhsWidth>>::Output: Unsigned + Add<U1>,
+    <<Width as Max<RhsWidth>>::Output as Add<U1>>::Output: ValidWidth,
 {
-    type Output = MaxBits<<Width as Sub<U1>>::Output>;
+    type Output = MaxBits<<<Width as Max<RhsWidth>>::Output as Add<U1>>::Output>;
 
     fn sub(self, rhs: MaxBits<RhsWidth>) -> Self::Output {
+        let mut data = GenericArray::<
+            u64,
+            Limbs<<<Width as Max<RhsWidth>>::Output as Add<U1>>::Output>,
+        >::default();
+        let mut borrow: i128 = 0;
+        for (i, limb) in data.iter_mut().enumerate() {
+            let diff = self.data.get(i).copied().unwrap_or(0) as i128
+                - rhs.data.get(i).copied().unwrap_or(0) as i128
+                - borrow;
+            if diff < 0 {
+                *limb = (diff + (1i128 << 64)) as u64;
+                borrow = 1;
+            } else {
+                *limb = diff as u64;
+                borrow = 0;
+            }
+        }
+        Self::Output {
+            data,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Width, RhsWidth> Mul<MaxBits<RhsWidth>> for MaxBits<Width>
+where
+    Width: ValidWidth + Add<RhsWidth>,
+    RhsWidth: ValidWidth,
+    <Width as Add<RhsWidth>>::Output: ValidWidth,
+{
+    type Output = MaxBits<<Width as Add<RhsWidth>>::Output>;
+
+    fn mul(self, rhs: MaxBits<RhsWidth>) -> Self::Output {
+        let mut data = GenericArray::<u64, Limbs<<Width as Add<RhsWidth>>::Output>>::default();
+        for (i, &a) in self.data.iter().enumerate() {
+            if a == 0 {
+                continue;
+            }
+            let mut carry: u128 = 0;
+            for (j, &b) in rhs.data.iter().enumerate() {
+                if i + j >= data.len() {
+                    break;
+                }
+                let product = (a as u128) * (b as u128) + data[i + j] as u128 + carry;
+                data[i + j] = product as u64;
+                carry = product >> 64;
+            }
+            let mut k = i + rhs.data.len();
+            while carry != 0 && k < data.len() {
+                let sum = data[k] as u128 + carry;
+                data[k] = sum as u64;
+                carry = sum >> 64;
+                k += 1;
+            }
+        }
+        Self::Output {
+            data,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Width, RhsWidth> Div<MaxBits<RhsWidth>> for MaxBits<Width>
+where
+    Width: ValidWidth,
+    RhsWidth: ValidWidth,
+{
+    type Output = MaxBits<Width>;
+
+    fn div(self, rhs: MaxBits<RhsWidth>) -> Self::Output {
+        let (quotient, _) = divmod::<Width, RhsWidth>(&self.data, &rhs.data);
+        Self::Output {
+            data: quotient,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Width, RhsWidth> Rem<MaxBits<RhsWidth>> for MaxBits<Width>
+where
+    Width: ValidWidth,
+    RhsWidth: ValidWidth,
+{
+    type Output = MaxBits<RhsWidth>;
+
+    fn rem(self, rhs: MaxBits<RhsWidth>) -> Self::Output {
+        let (_, remainder) = divmod::<Width, RhsWidth>(&self.data, &rhs.data);
         Self::Output {
-            data: self.data + rhs.data,
+            data: remainder,
             _marker: PhantomData,
         }
     }
 }
 
 fn main() {
-    let bits = MaxBits::<U16>::new(0xffff).unwrap();
+    let bits = MaxBits::<U16>::from_u64(0xffff).unwrap();
     let shifted: MaxBits<U32> = (bits << U15::new()).widen();
-    let bigger = MaxBits::<U48>::new(0xcdbaef123456).unwrap();
+    let bigger = MaxBits::<U48>::from_u64(0xcdbaef123456).unwrap();
     let summed: MaxBits<U49> = shifted + bigger;
 
-    let bits = MaxBits::<U128>::new(u128::MAX).unwrap();
-    let one = MaxBits::<U1>::new(1).unwrap();
-    let eighty_mask: MaxBits<U80> = (one << U80::new()) - one;
-    let bits: MaxBits<U80> = bits & eighty_mask;
-    let six_mask: MaxBits<U6> = (one << U6::new()) - one;
-    let six_bits: MaxBits<U6> = (eighty_mask & (six_mask << U74::new())) >> U74::new();
+    let bits = MaxBits::<U128>::new(GenericArray::from([u64::MAX, u64::MAX])).unwrap();
+    let one = MaxBits::<U1>::from_u64(1).unwrap();
+    // Sub's output width grows by one, same as Add, so on top of the one bit
+    // the shift already adds, these masks come out two bits wider than the
+    // span they actually hold.
+    let eighty_mask: MaxBits<U82> = (one << U80::new()) - one;
+    let bits: MaxBits<U82> = bits & eighty_mask;
+    let six_mask: MaxBits<U8> = (one << U6::new()) - one;
+    let six_bits: MaxBits<U8> = (eighty_mask & (six_mask << U74::new())) >> U74::new();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Regression coverage for a `divmod` bug: the remainder register has no
+    // spare bit, so when `DenWidth` lands on a limb boundary (e.g. U64,
+    // U128) a top-bit-set divisor used to silently drop the overflow bit
+    // and corrupt the quotient. Small or low-bit divisors of the same width
+    // happen to work, which is what let the bug through originally, so
+    // these divisors specifically have their top bit set.
+    #[test]
+    fn div_rem_by_top_bit_set_divisor_at_limb_boundary() {
+        let num = MaxBits::<U128>::new(GenericArray::from([
+            0x1234_5678_9abc_def0u64,
+            0x0000_0000_0000_0007u64,
+        ]))
+        .unwrap();
+        let den = MaxBits::<U64>::from_u64(0xFFFF_FFFF_FFFF_FFFF).unwrap();
+
+        let quotient = num / den;
+        let remainder = num % den;
+
+        let quotient_limbs = quotient.into_inner();
+        let quotient_value = quotient_limbs[0] as u128 | ((quotient_limbs[1] as u128) << 64);
+        assert_eq!(quotient_value, 7);
+        assert_eq!(remainder.into_inner()[0], 1_311_768_467_463_790_327);
+    }
+
+    #[test]
+    fn mul_multi_limb_happy_path() {
+        let a = MaxBits::<U64>::from_u64(u64::MAX).unwrap();
+        let b = MaxBits::<U64>::from_u64(2).unwrap();
+        let product: MaxBits<U128> = a * b;
+
+        let limbs = product.into_inner();
+        let value = limbs[0] as u128 | ((limbs[1] as u128) << 64);
+        assert_eq!(value, u64::MAX as u128 * 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero")]
+    fn div_by_zero_panics() {
+        let num = MaxBits::<U64>::from_u64(5).unwrap();
+        let den = MaxBits::<U64>::from_u64(0).unwrap();
+        let _ = num / den;
+    }
+
+    #[test]
+    fn div_rem_happy_path() {
+        let num = MaxBits::<U64>::from_u64(100).unwrap();
+        let den = MaxBits::<U64>::from_u64(7).unwrap();
+
+        assert_eq!((num / den).into_inner()[0], 14);
+        assert_eq!((num % den).into_inner()[0], 2);
+    }
+
+    // Regression coverage for a `narrow` bug: `resize` only copies as many
+    // limbs as the target width has room for, so a value with a nonzero
+    // high limb beyond the narrowed width used to slip past the fits
+    // check entirely instead of being rejected.
+    #[test]
+    fn narrow_rejects_value_with_nonzero_dropped_limb() {
+        let value = MaxBits::<U128>::new(GenericArray::from([0u64, 1u64])).unwrap();
+        assert!(value.narrow::<U60>().is_none());
+    }
+
+    #[test]
+    fn narrow_accepts_value_that_fits() {
+        let value = MaxBits::<U128>::from_u64(42).unwrap();
+        let narrowed = value.narrow::<U60>().unwrap();
+        assert_eq!(narrowed.into_inner()[0], 42);
+    }
+
+    #[test]
+    fn set_clear_extract_range_round_trip() {
+        let zero = MaxBits::<U8>::from_u64(0).unwrap();
+
+        let nibble_set: MaxBits<U8> = zero.set_range(U4::new(), U8::new());
+        assert_eq!(nibble_set.into_inner()[0], 0xf0);
+
+        let cleared = nibble_set.clear_range(U6::new(), U8::new());
+        assert_eq!(cleared.into_inner()[0], 0x30);
+
+        let extracted: MaxBits<U4> = cleared.extract_range(U4::new(), U8::new());
+        assert_eq!(extracted.into_inner()[0], 0x3);
+    }
+
+    #[test]
+    fn widen_preserves_value() {
+        let value = MaxBits::<U60>::from_u64(0xabc).unwrap();
+        let widened = value.widen::<U128>();
+        assert_eq!(widened.into_inner()[0], 0xabc);
+    }
+
+    #[test]
+    fn add_sub_shl_shr_bitwise_round_trip() {
+        let a = MaxBits::<U64>::from_u64(0x0f0f_0f0f_0f0f_0f0f).unwrap();
+        let b = MaxBits::<U64>::from_u64(0xf0f0_f0f0_f0f0_f0f0).unwrap();
+
+        let sum: MaxBits<U65> = a + b;
+        assert_eq!(sum.into_inner()[0], u64::MAX);
+
+        let difference: MaxBits<U66> = sum - a;
+        assert_eq!(difference.into_inner()[0], b.into_inner()[0]);
+
+        let shifted = a << U4::new();
+        assert_eq!(shifted.into_inner()[0], 0xf0f0_f0f0_f0f0_f0f0);
+
+        let shifted_back = shifted >> U4::new();
+        assert_eq!(shifted_back.into_inner()[0], a.into_inner()[0]);
+
+        assert_eq!((a | b).into_inner()[0], u64::MAX);
+        assert_eq!((a & b).into_inner()[0], 0);
+        assert_eq!((a ^ b).into_inner()[0], u64::MAX);
+    }
+
+    #[test]
+    fn bitset_contains_min_max_and_iteration() {
+        let value = MaxBits::<U8>::from_u64(0b1010_0001).unwrap();
+
+        assert!(value.contains(U0::new()));
+        assert!(!value.contains(U1::new()));
+        assert!(value.contains(U5::new()));
+        assert!(value.contains(U7::new()));
+
+        assert_eq!(value.min_set_bit().unwrap().to_u32(), 0);
+        assert_eq!(value.max_set_bit().unwrap().to_u32(), 7);
+
+        let mut set_bits = value.set_bits();
+        assert_eq!(set_bits.next().unwrap().to_u32(), 0);
+        assert_eq!(set_bits.next().unwrap().to_u32(), 5);
+        assert_eq!(set_bits.next().unwrap().to_u32(), 7);
+        assert!(set_bits.next().is_none());
+    }
+
+    // `leading_zeros`/`trailing_zeros` used to be relative to the limb
+    // array's full backing capacity rather than the logical `Width`, so a
+    // `Width` that isn't a multiple of 64 (like U60, backed by one 64-bit
+    // limb with 4 padding bits) reported counts inflated by the padding.
+    #[test]
+    fn leading_and_trailing_zeros_are_relative_to_width() {
+        let one = MaxBits::<U60>::from_u64(1).unwrap();
+        assert_eq!(one.leading_zeros(), 59);
+        assert_eq!(one.trailing_zeros(), 0);
+
+        let zero = MaxBits::<U60>::from_u64(0).unwrap();
+        assert_eq!(zero.leading_zeros(), 60);
+        assert_eq!(zero.trailing_zeros(), 60);
+    }
+
+    #[test]
+    fn byte_and_hex_round_trip() {
+        let value = MaxBits::<U32>::from_u64(0x1234_5678).unwrap();
+
+        let be = value.to_be_bytes();
+        assert_eq!(&be[..], &[0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(MaxBits::<U32>::from_be_bytes(&be).unwrap(), value);
+
+        let le = value.to_le_bytes();
+        assert_eq!(&le[..], &[0x78, 0x56, 0x34, 0x12]);
+        assert_eq!(MaxBits::<U32>::from_le_bytes(&le).unwrap(), value);
+
+        let hex = value.to_hex();
+        assert_eq!(&hex[..], b"12345678");
+        assert_eq!(MaxBits::<U32>::from_hex(&hex).unwrap(), value);
+    }
 }